@@ -0,0 +1,44 @@
+use analysis::imports::Imports;
+use analysis::properties::{self, BuilderProperty, Property};
+use analysis::signals;
+use analysis::signatures::Signatures;
+use analysis::trampolines;
+use config::gobjects::GObject;
+use env::Env;
+use library;
+
+pub struct Info {
+    pub properties: Vec<Property>,
+    pub notify_signals: Vec<signals::Info>,
+    pub builder_properties: Vec<BuilderProperty>,
+}
+
+pub fn analyze(
+    env: &Env,
+    props: &[library::Property],
+    type_tid: library::TypeId,
+    generate_trait: bool,
+    trampolines: &mut trampolines::Trampolines,
+    obj: &GObject,
+    imports: &mut Imports,
+    signatures: &Signatures,
+    deps: &[library::TypeId],
+) -> Info {
+    let (properties, notify_signals, builder_properties) = properties::analyze(
+        env,
+        props,
+        type_tid,
+        generate_trait,
+        trampolines,
+        obj,
+        imports,
+        signatures,
+        deps,
+    );
+
+    Info {
+        properties: properties,
+        notify_signals: notify_signals,
+        builder_properties: builder_properties,
+    }
+}
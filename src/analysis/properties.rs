@@ -13,7 +13,7 @@ use nameutil;
 use traits::*;
 use version::Version;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Property {
     pub name: String,
     pub var_name: String,
@@ -27,9 +27,38 @@ pub struct Property {
     pub set_in_ref_mode: RefMode,
     pub version: Option<Version>,
     pub deprecated_version: Option<Version>,
+    pub deprecated_note: Option<String>,
+    pub doc_cfg: bool,
     pub bound: Option<Bound>,
 }
 
+/// A single fluent setter on a generated `FooBuilder`.
+///
+/// `type_name` is resolved once in `analyze` (where `Env` is available) so
+/// that codegen can stay pure string manipulation.
+#[derive(Clone, Debug)]
+pub struct BuilderProperty {
+    pub name: String,
+    pub var_name: String,
+    pub type_name: String,
+    pub nullable: library::Nullable,
+    pub conversion: PropertyConversion,
+    pub bound: Option<Bound>,
+}
+
+impl BuilderProperty {
+    fn from_setter(setter: &Property, type_name: String) -> BuilderProperty {
+        BuilderProperty {
+            name: setter.name.clone(),
+            var_name: setter.var_name.clone(),
+            type_name: type_name,
+            nullable: setter.nullable,
+            conversion: setter.conversion,
+            bound: setter.bound.clone(),
+        }
+    }
+}
+
 pub fn analyze(
     env: &Env,
     props: &[library::Property],
@@ -40,9 +69,10 @@ pub fn analyze(
     imports: &mut Imports,
     signatures: &Signatures,
     deps: &[library::TypeId],
-) -> (Vec<Property>, Vec<signals::Info>) {
+) -> (Vec<Property>, Vec<signals::Info>, Vec<BuilderProperty>) {
     let mut properties = Vec::new();
     let mut notify_signals = Vec::new();
+    let mut builder_properties = Vec::new();
 
     for prop in props {
         let configured_properties = obj.properties.matched(&prop.name);
@@ -54,7 +84,7 @@ pub fn analyze(
             continue;
         }
 
-        let (getter, setter, notify_signal) = analyze_property(
+        let (getter, setter, builder_setter, notify_signal) = analyze_property(
             env,
             prop,
             type_tid,
@@ -71,6 +101,13 @@ pub fn analyze(
             notify_signals.push(notify_signal);
         }
 
+        if let Some(ref builder_setter) = builder_setter {
+            if should_include_in_builder(obj, prop) {
+                let type_name = rust_type(env, builder_setter.typ).into_string();
+                builder_properties.push(BuilderProperty::from_setter(builder_setter, type_name));
+            }
+        }
+
         if getter.is_none() && setter.is_none() {
             continue;
         }
@@ -107,7 +144,17 @@ pub fn analyze(
         }
     }
 
-    (properties, notify_signals)
+    (properties, notify_signals, builder_properties)
+}
+
+/// Whether `prop` should get a fluent setter on the type's generated builder.
+///
+/// Builders are opt-in per `GObject`; by default only construct-only
+/// properties are collected, since those have no other way to be set before
+/// construction. `builder_all_writable` widens that to every writable
+/// property.
+fn should_include_in_builder(obj: &GObject, prop: &library::Property) -> bool {
+    obj.generate_builder && (prop.construct_only || obj.builder_all_writable)
 }
 
 fn analyze_property(
@@ -121,7 +168,12 @@ fn analyze_property(
     imports: &mut Imports,
     signatures: &Signatures,
     deps: &[library::TypeId],
-) -> (Option<Property>, Option<Property>, Option<signals::Info>) {
+) -> (
+    Option<Property>,
+    Option<Property>,
+    Option<Property>,
+    Option<signals::Info>,
+) {
     let name = prop.name.clone();
     let type_ = env.type_(prop.typ);
 
@@ -137,29 +189,29 @@ fn analyze_property(
     let check_get_func_name = format!("get_{}", name_for_func);
     let check_set_func_name = format!("set_{}", name_for_func);
 
-    let mut readable = prop.readable;
-    let mut writable = if prop.construct_only {
-        false
-    } else {
-        prop.writable
-    };
+    let get_overridden =
+        has_overriding_signature(env, &check_get_func_name, signatures, deps, prop_version);
+    let set_overridden =
+        has_overriding_signature(env, &check_set_func_name, signatures, deps, prop_version);
 
-    if readable {
-        let (has, version) =
-            Signature::has_by_name_and_in_deps(env, &check_get_func_name, signatures, deps);
-        if has && (env.is_totally_deprecated(version) || version <= prop_version) {
-            readable = false;
-        }
+    let mut readable = prop.readable;
+    if readable && get_overridden {
+        readable = false;
     }
-    if writable {
-        let (has, version) =
-            Signature::has_by_name_and_in_deps(env, &check_set_func_name, signatures, deps);
-        if has && (env.is_totally_deprecated(version) || version <= prop_version) {
-            writable = false;
-        }
+
+    // `buildable` is the setter candidate shared by the regular setter and the
+    // builder: it only cares whether the property is writable at all and
+    // whether a hand-written setter already overrides it, not whether it's
+    // construct-only.
+    let mut buildable = prop.writable;
+    if buildable && set_overridden {
+        buildable = false;
     }
 
-    let default_value = get_type_default_value(env, prop.typ, type_);
+    let writable = buildable && !prop.construct_only;
+
+    let default_value = configured_default_value(configured_properties)
+        .or_else(|| get_type_default_value(env, prop.typ, type_));
     if default_value.is_none() && readable {
         readable = false;
         let owner_name = rust_type(env, type_tid).into_string();
@@ -169,6 +221,7 @@ fn analyze_property(
             owner_name
         );
     }
+    let doc_cfg = obj.generate_doc_cfg && prop_version.is_some();
     let conversion = PropertyConversion::of(type_);
     let get_out_ref_mode = RefMode::of(env, prop.typ, library::ParameterDirection::Return);
     let mut set_in_ref_mode = RefMode::of(env, prop.typ, library::ParameterDirection::In);
@@ -190,13 +243,15 @@ fn analyze_property(
             set_in_ref_mode: set_in_ref_mode,
             version: prop_version,
             deprecated_version: prop.deprecated_version,
+            deprecated_note: prop.doc_deprecated.clone(),
+            doc_cfg: doc_cfg,
             bound: None,
         })
     } else {
         None
     };
 
-    let setter = if writable {
+    let builder_setter = if buildable {
         let bound = Bound::get_for_property_setter(env, &var_name, prop.typ, nullable);
         Some(Property {
             name: name.clone(),
@@ -211,12 +266,22 @@ fn analyze_property(
             set_in_ref_mode: set_in_ref_mode,
             version: prop_version,
             deprecated_version: prop.deprecated_version,
+            deprecated_note: prop.doc_deprecated.clone(),
+            doc_cfg: doc_cfg,
             bound: bound,
         })
     } else {
         None
     };
 
+    // The regular (non-builder) setter is only emitted post-construction, so
+    // it's the same candidate as `builder_setter` minus construct-only props.
+    let setter = if writable {
+        builder_setter.clone()
+    } else {
+        None
+    };
+
     let mut used_types: Vec<String> = Vec::with_capacity(4);
     let trampoline_name = trampolines::analyze(
         env,
@@ -269,13 +334,40 @@ fn analyze_property(
             trampoline_name: trampoline_name,
             version: prop_version,
             deprecated_version: prop.deprecated_version,
+            deprecated_note: prop.doc_deprecated.clone(),
             doc_hidden: false,
+            doc_cfg: doc_cfg,
         })
     } else {
         None
     };
 
-    (getter, setter, notify_signal)
+    (getter, setter, builder_setter, notify_signal)
+}
+
+/// Whether a hand-written `func_name` already exists somewhere in `deps` and
+/// is either totally deprecated or available no later than `prop_version`,
+/// making it redundant (and for deprecated overrides, actively wrong) to
+/// auto-generate an accessor for the same property. A hand-written function
+/// that only appears in a newer version than `prop_version` does not count.
+fn has_overriding_signature(
+    env: &Env,
+    func_name: &str,
+    signatures: &Signatures,
+    deps: &[library::TypeId],
+    prop_version: Option<Version>,
+) -> bool {
+    let (has, version) = Signature::has_by_name_and_in_deps(env, func_name, signatures, deps);
+    has && (env.is_totally_deprecated(version) || version <= prop_version)
+}
+
+/// A maintainer-supplied escape hatch for property types `get_type_default_value`
+/// can't synthesize a default for (boxed types, `GVariant`, fixed arrays, ...).
+fn configured_default_value(configured_properties: &[&config::properties::Property]) -> Option<String> {
+    configured_properties
+        .iter()
+        .filter_map(|f| f.default_value.clone())
+        .next()
 }
 
 pub fn get_type_default_value(
@@ -346,3 +438,37 @@ impl Default for PropertyConversion {
         PropertyConversion::Direct
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn configured(default_value: Option<&str>) -> config::properties::Property {
+        config::properties::Property {
+            name: "variant-value".into(),
+            ignore: false,
+            version: None,
+            default_value: default_value.map(|s| s.to_string()),
+        }
+    }
+
+    #[test]
+    fn configured_default_value_prefers_the_first_configured_override() {
+        let with_default = configured(Some("None::<&glib::Variant>"));
+        let without_default = configured(None);
+        let configured_properties = [&with_default, &without_default];
+
+        assert_eq!(
+            configured_default_value(&configured_properties),
+            Some("None::<&glib::Variant>".to_string())
+        );
+    }
+
+    #[test]
+    fn configured_default_value_is_none_without_an_override() {
+        let without_default = configured(None);
+        let configured_properties = [&without_default];
+
+        assert_eq!(configured_default_value(&configured_properties), None);
+    }
+}
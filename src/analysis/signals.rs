@@ -0,0 +1,13 @@
+use version::Version;
+
+#[derive(Debug)]
+pub struct Info {
+    pub connect_name: String,
+    pub signal_name: String,
+    pub trampoline_name: Result<String, String>,
+    pub version: Option<Version>,
+    pub deprecated_version: Option<Version>,
+    pub deprecated_note: Option<String>,
+    pub doc_hidden: bool,
+    pub doc_cfg: bool,
+}
@@ -0,0 +1,147 @@
+use std::io::{Result, Write};
+
+use analysis::properties::{BuilderProperty, PropertyConversion};
+
+/// The Rust type a generated builder setter should take for `prop`.
+///
+/// Enum/bitfield-backed properties take their real type directly (they're
+/// converted to `Value` internally); everything else takes a reference,
+/// optional when the property is nullable.
+pub fn setter_param_type(prop: &BuilderProperty) -> String {
+    match prop.conversion {
+        PropertyConversion::Bitflag | PropertyConversion::AsI32 => prop.type_name.clone(),
+        PropertyConversion::Direct => if prop.nullable.0 {
+            format!("Option<&{}>", prop.type_name)
+        } else {
+            format!("&{}", prop.type_name)
+        },
+    }
+}
+
+pub fn write_builder_setter<W: Write>(w: &mut W, prop: &BuilderProperty) -> Result<()> {
+    let param_type = setter_param_type(prop);
+    writeln!(
+        w,
+        "pub fn {}(mut self, {}: {}) -> Self {{",
+        prop.name, prop.var_name, param_type
+    )?;
+    match prop.conversion {
+        PropertyConversion::Bitflag | PropertyConversion::AsI32 => writeln!(
+            w,
+            "    self.properties.push((\"{}\", (&({} as i32)).to_value()));",
+            prop.name, prop.var_name
+        )?,
+        PropertyConversion::Direct => writeln!(
+            w,
+            "    self.properties.push((\"{}\", {}.to_value()));",
+            prop.name, prop.var_name
+        )?,
+    }
+    writeln!(w, "    self")?;
+    writeln!(w, "}}")
+}
+
+pub fn write_builder<W: Write>(
+    w: &mut W,
+    type_name: &str,
+    properties: &[BuilderProperty],
+) -> Result<()> {
+    writeln!(w, "pub struct {}Builder {{", type_name)?;
+    writeln!(w, "    properties: Vec<(&'static str, Box<ToValue>)>,")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    writeln!(w, "impl {}Builder {{", type_name)?;
+    writeln!(w, "    fn new() -> Self {{")?;
+    writeln!(w, "        Self {{ properties: vec![] }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w)?;
+    for prop in properties {
+        write_builder_setter(w, prop)?;
+        writeln!(w)?;
+    }
+    writeln!(w, "    pub fn build(self) -> {} {{", type_name)?;
+    writeln!(
+        w,
+        "        let properties: Vec<(&str, &ToValue)> = self.properties.iter()"
+    )?;
+    writeln!(w, "            .map(|&(name, ref value)| (name, value.as_ref() as &ToValue))")?;
+    writeln!(w, "            .collect();")?;
+    writeln!(
+        w,
+        "        glib::Object::new({}::static_type(), &properties)",
+        type_name
+    )?;
+    writeln!(w, "            .expect(\"object new\")")?;
+    writeln!(w, "            .downcast()")?;
+    writeln!(w, "            .expect(\"downcast\")")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    writeln!(w, "impl {} {{", type_name)?;
+    writeln!(w, "    pub fn builder() -> {}Builder {{", type_name)?;
+    writeln!(w, "        {}Builder::new()", type_name)?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use analysis::bounds::Bound;
+    use library;
+
+    fn direct_property() -> BuilderProperty {
+        BuilderProperty {
+            name: "label".into(),
+            var_name: "label".into(),
+            type_name: "str".into(),
+            nullable: library::Nullable(false),
+            conversion: PropertyConversion::Direct,
+            bound: None,
+        }
+    }
+
+    #[test]
+    fn setter_param_type_for_direct_property_is_a_reference() {
+        assert_eq!(setter_param_type(&direct_property()), "&str");
+    }
+
+    #[test]
+    fn setter_param_type_for_nullable_direct_property_is_optional() {
+        let mut prop = direct_property();
+        prop.nullable = library::Nullable(true);
+        assert_eq!(setter_param_type(&prop), "Option<&str>");
+    }
+
+    #[test]
+    fn setter_param_type_for_enum_property_is_the_bare_enum_type() {
+        let mut prop = direct_property();
+        prop.type_name = "Orientation".into();
+        prop.conversion = PropertyConversion::AsI32;
+        assert_eq!(setter_param_type(&prop), "Orientation");
+    }
+
+    #[test]
+    fn setter_param_type_for_bitfield_property_is_the_bare_flags_type() {
+        let mut prop = direct_property();
+        prop.type_name = "DialogFlags".into();
+        prop.conversion = PropertyConversion::Bitflag;
+        assert_eq!(setter_param_type(&prop), "DialogFlags");
+    }
+
+    #[test]
+    fn write_builder_setter_for_enum_property_is_typed_not_to_value() {
+        let mut prop = direct_property();
+        prop.name = "orientation".into();
+        prop.var_name = "orientation".into();
+        prop.type_name = "Orientation".into();
+        prop.conversion = PropertyConversion::AsI32;
+
+        let mut buf = Vec::new();
+        write_builder_setter(&mut buf, &prop).unwrap();
+        let out = String::from_utf8(buf).unwrap();
+
+        assert!(out.contains("fn orientation(mut self, orientation: Orientation) -> Self {"));
+        assert!(!out.contains("ToValue"));
+    }
+}
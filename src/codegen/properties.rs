@@ -0,0 +1,226 @@
+use std::io::{Result, Write};
+
+use analysis::properties::Property;
+use analysis::rust_type::rust_type;
+use analysis::signals;
+use env::Env;
+use version::Version;
+
+/// Writes a `#[deprecated(since = "...", note = "...")]` attribute for `prop`,
+/// or nothing if the property isn't deprecated.
+pub fn write_deprecated_attribute<W: Write>(w: &mut W, prop: &Property) -> Result<()> {
+    write_attribute(w, prop.deprecated_version, prop.deprecated_note.as_ref())
+}
+
+/// Writes a `#[deprecated(since = "...", note = "...")]` attribute for `info`,
+/// or nothing if the signal isn't deprecated.
+pub fn write_deprecated_attribute_for_signal<W: Write>(
+    w: &mut W,
+    info: &signals::Info,
+) -> Result<()> {
+    write_attribute(w, info.deprecated_version, info.deprecated_note.as_ref())
+}
+
+fn write_attribute<W: Write>(
+    w: &mut W,
+    deprecated_version: Option<Version>,
+    note: Option<&String>,
+) -> Result<()> {
+    if let Some(since) = deprecated_version {
+        match note {
+            Some(note) => writeln!(
+                w,
+                "#[deprecated(since = \"{}\", note = \"{}\")]",
+                since, note
+            ),
+            None => writeln!(w, "#[deprecated(since = \"{}\")]", since),
+        }
+    } else {
+        Ok(())
+    }
+}
+
+/// Writes a `#[cfg_attr(feature = "dox", doc(cfg(feature = "vX_Y")))]` attribute
+/// for `prop`, or nothing if `prop` isn't gated behind `doc_cfg`.
+pub fn write_doc_cfg_attribute<W: Write>(w: &mut W, prop: &Property) -> Result<()> {
+    write_doc_cfg(w, prop.doc_cfg, prop.version)
+}
+
+/// Writes a `#[cfg_attr(feature = "dox", doc(cfg(feature = "vX_Y")))]` attribute
+/// for `info`, or nothing if `info` isn't gated behind `doc_cfg`.
+pub fn write_doc_cfg_attribute_for_signal<W: Write>(w: &mut W, info: &signals::Info) -> Result<()> {
+    write_doc_cfg(w, info.doc_cfg, info.version)
+}
+
+fn write_doc_cfg<W: Write>(w: &mut W, doc_cfg: bool, version: Option<Version>) -> Result<()> {
+    if !doc_cfg {
+        return Ok(());
+    }
+    if let Some(version) = version {
+        writeln!(
+            w,
+            "#[cfg_attr(feature = \"dox\", doc(cfg(feature = \"v{}_{}\")))]",
+            version.major, version.minor
+        )
+    } else {
+        Ok(())
+    }
+}
+
+pub fn generate_getter<W: Write>(w: &mut W, env: &Env, prop: &Property) -> Result<()> {
+    write_deprecated_attribute(w, prop)?;
+    write_doc_cfg_attribute(w, prop)?;
+    let type_str = rust_type(env, prop.typ).into_string();
+    writeln!(w, "pub fn {}(&self) -> {} {{", prop.func_name, type_str)?;
+    writeln!(
+        w,
+        "    self.get_property(\"{}\").unwrap().get().unwrap()",
+        prop.name
+    )?;
+    writeln!(w, "}}")
+}
+
+pub fn generate_setter<W: Write>(w: &mut W, env: &Env, prop: &Property) -> Result<()> {
+    write_deprecated_attribute(w, prop)?;
+    write_doc_cfg_attribute(w, prop)?;
+    let type_str = rust_type(env, prop.typ).into_string();
+    writeln!(
+        w,
+        "pub fn {}(&self, {}: {}) {{",
+        prop.func_name, prop.var_name, type_str
+    )?;
+    writeln!(
+        w,
+        "    self.set_property(\"{}\", &{}).unwrap()",
+        prop.name, prop.var_name
+    )?;
+    writeln!(w, "}}")
+}
+
+pub fn generate_notify_connector<W: Write>(w: &mut W, info: &signals::Info) -> Result<()> {
+    write_deprecated_attribute_for_signal(w, info)?;
+    write_doc_cfg_attribute_for_signal(w, info)?;
+    writeln!(
+        w,
+        "pub fn {}<F: Fn(&Self) + 'static>(&self, f: F) -> SignalHandlerId {{",
+        info.connect_name
+    )?;
+    writeln!(w, "    // connect to \"{}\"", info.signal_name)?;
+    writeln!(w, "}}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use analysis::bounds::Bound;
+    use analysis::ref_mode::RefMode;
+    use analysis::properties::PropertyConversion;
+    use library;
+
+    fn deprecated_property() -> Property {
+        Property {
+            name: "active".into(),
+            var_name: "active".into(),
+            typ: library::TypeId { ns_id: 0, id: 0 },
+            is_get: true,
+            func_name: "get_property_active".into(),
+            nullable: library::Nullable(false),
+            conversion: PropertyConversion::Direct,
+            default_value: Some("&false".into()),
+            get_out_ref_mode: RefMode::ByValue,
+            set_in_ref_mode: RefMode::ByValue,
+            version: None,
+            deprecated_version: Some(Version::new(1, 2, 0)),
+            deprecated_note: Some("Use `is-active` instead".into()),
+            doc_cfg: false,
+            bound: None,
+        }
+    }
+
+    fn deprecated_signal_info() -> signals::Info {
+        signals::Info {
+            connect_name: "connect_property_active_notify".into(),
+            signal_name: "notify::active".into(),
+            trampoline_name: Ok("active_trampoline".into()),
+            version: None,
+            deprecated_version: Some(Version::new(1, 2, 0)),
+            deprecated_note: Some("Use `is-active` instead".into()),
+            doc_hidden: false,
+            doc_cfg: false,
+        }
+    }
+
+    fn versioned_property() -> Property {
+        Property {
+            doc_cfg: true,
+            version: Some(Version::new(1, 4, 0)),
+            deprecated_version: None,
+            deprecated_note: None,
+            ..deprecated_property()
+        }
+    }
+
+    #[test]
+    fn write_deprecated_attribute_includes_since_and_note() {
+        let prop = deprecated_property();
+        let mut buf = Vec::new();
+        write_deprecated_attribute(&mut buf, &prop).unwrap();
+        let out = String::from_utf8(buf).unwrap();
+        assert_eq!(
+            out,
+            "#[deprecated(since = \"1.2\", note = \"Use `is-active` instead\")]\n"
+        );
+    }
+
+    #[test]
+    fn write_deprecated_attribute_for_signal_includes_since_and_note() {
+        let info = deprecated_signal_info();
+        let mut buf = Vec::new();
+        write_deprecated_attribute_for_signal(&mut buf, &info).unwrap();
+        let out = String::from_utf8(buf).unwrap();
+        assert_eq!(
+            out,
+            "#[deprecated(since = \"1.2\", note = \"Use `is-active` instead\")]\n"
+        );
+    }
+
+    #[test]
+    fn write_deprecated_attribute_omits_note_when_absent() {
+        let mut prop = deprecated_property();
+        prop.deprecated_note = None;
+        let mut buf = Vec::new();
+        write_deprecated_attribute(&mut buf, &prop).unwrap();
+        let out = String::from_utf8(buf).unwrap();
+        assert_eq!(out, "#[deprecated(since = \"1.2\")]\n");
+    }
+
+    #[test]
+    fn write_deprecated_attribute_is_empty_when_not_deprecated() {
+        let mut prop = deprecated_property();
+        prop.deprecated_version = None;
+        let mut buf = Vec::new();
+        write_deprecated_attribute(&mut buf, &prop).unwrap();
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn write_doc_cfg_attribute_emits_feature_for_versioned_property() {
+        let prop = versioned_property();
+        let mut buf = Vec::new();
+        write_doc_cfg_attribute(&mut buf, &prop).unwrap();
+        let out = String::from_utf8(buf).unwrap();
+        assert_eq!(
+            out,
+            "#[cfg_attr(feature = \"dox\", doc(cfg(feature = \"v1_4\")))]\n"
+        );
+    }
+
+    #[test]
+    fn write_doc_cfg_attribute_is_empty_when_disabled() {
+        let mut prop = versioned_property();
+        prop.doc_cfg = false;
+        let mut buf = Vec::new();
+        write_doc_cfg_attribute(&mut buf, &prop).unwrap();
+        assert!(buf.is_empty());
+    }
+}
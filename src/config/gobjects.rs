@@ -0,0 +1,14 @@
+use config::properties::Properties;
+
+#[derive(Clone, Debug, Default)]
+pub struct GObject {
+    pub properties: Properties,
+    /// Emit `#[cfg_attr(feature = "dox", doc(cfg(feature = "vX_Y")))]` next to
+    /// version `#[cfg(...)]` guards on generated property accessors.
+    pub generate_doc_cfg: bool,
+    /// Emit a `FooBuilder` for this type's construct-only properties.
+    pub generate_builder: bool,
+    /// Widen the builder to cover every writable property, not just
+    /// construct-only ones.
+    pub builder_all_writable: bool,
+}
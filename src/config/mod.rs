@@ -0,0 +1,2 @@
+pub mod gobjects;
+pub mod properties;
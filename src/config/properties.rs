@@ -0,0 +1,58 @@
+use version::Version;
+
+#[derive(Clone, Debug, Default)]
+pub struct Property {
+    pub name: String,
+    pub ignore: bool,
+    pub version: Option<Version>,
+    /// An explicit getter default value, for types `get_type_default_value`
+    /// can't synthesize one for (boxed types, `GVariant`, fixed arrays, ...).
+    pub default_value: Option<String>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Properties(Vec<Property>);
+
+impl Properties {
+    pub fn new(properties: Vec<Property>) -> Properties {
+        Properties(properties)
+    }
+
+    /// Returns the configured entries whose `name` matches `name`.
+    pub fn matched(&self, name: &str) -> Vec<&Property> {
+        self.0.iter().filter(|f| f.name == name).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matched_filters_by_name() {
+        let properties = Properties::new(vec![
+            Property {
+                name: "active".into(),
+                ignore: true,
+                version: None,
+                default_value: None,
+            },
+            Property {
+                name: "label".into(),
+                ignore: false,
+                version: None,
+                default_value: None,
+            },
+        ]);
+
+        let matched = properties.matched("active");
+        assert_eq!(matched.len(), 1);
+        assert!(matched[0].ignore);
+
+        let matched = properties.matched("label");
+        assert_eq!(matched.len(), 1);
+        assert!(!matched[0].ignore);
+
+        assert!(properties.matched("unknown").is_empty());
+    }
+}